@@ -95,7 +95,6 @@
     clippy::string_lit_as_bytes,
     clippy::string_lit_chars_any,
     clippy::string_slice,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::suspicious_xor_used_as_pow,
@@ -155,12 +154,28 @@ mod trace;
 
 use std::{
     error::Error,
+    fmt, iter,
     sync::atomic::{AtomicUsize, Ordering},
 };
 
-use derive_more::with_trait::{AsMut, AsRef, Display};
+#[cfg(feature = "backtrace")]
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    env,
+    sync::Arc,
+};
+#[cfg(all(test, feature = "backtrace"))]
+use std::cell::Cell;
+
+use derive_more::with_trait::{AsMut, AsRef};
 use sealed::sealed;
 
+// `serde_json` is only exercised by the `Frame`/`Trace` serialization specs
+// in `trace.rs`, which are gated on the `serde` feature. Keep it a recognized
+// dependency for test builds that don't enable that feature too.
+#[cfg(all(test, not(feature = "serde")))]
+use serde_json as _;
+
 #[doc(inline)]
 pub use self::trace::*;
 
@@ -171,12 +186,20 @@ pub use self::trace::*;
 pub static DEFAULT_FRAMES_CAPACITY: AtomicUsize = AtomicUsize::new(10);
 
 /// Wrapper for an arbitrary error holding the captured error trace along.
-#[derive(AsMut, AsRef, Clone, Debug, Display)]
-#[display("{err}")]
+///
+/// Most of its API has no bound on the wrapped `E` at all, but
+/// [`Display`](fmt::Display) and [`Error`] themselves require `E: Error`,
+/// since rendering the alternate-flag source chain needs `Error::source()`.
+#[derive(AsMut, AsRef, Clone, Debug)]
 pub struct Traced<E: ?Sized> {
     /// Captured error trace.
     trace: Trace,
 
+    /// Captured OS [`Backtrace`] of the point where this [`Traced`] wrapper
+    /// has been created for the first time.
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<Arc<Backtrace>>,
+
     /// Original error.
     #[as_mut]
     #[as_ref]
@@ -192,6 +215,18 @@ impl<E: ?Sized> Traced<E> {
     pub const fn trace(&self) -> &Trace {
         &self.trace
     }
+
+    /// Returns the captured OS [`Backtrace`] of the point where this
+    /// [`Traced`] wrapper has been created for the first time, if any.
+    ///
+    /// Respects the `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE` environment
+    /// variables, same as [`Backtrace::capture()`] does. Returns `None` if
+    /// backtrace collection is disabled or not supported.
+    #[cfg(feature = "backtrace")]
+    #[must_use]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_deref()
+    }
 }
 
 impl<E> Traced<E> {
@@ -213,7 +248,12 @@ impl<E> Traced<E> {
     /// wrapper.
     #[must_use]
     pub const fn compose(error: E, trace: Trace) -> Self {
-        Self { err: error, trace }
+        Self {
+            err: error,
+            trace,
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
+        }
     }
 }
 
@@ -246,6 +286,129 @@ impl<E: Error + ?Sized> Error for Traced<E> {
     }
 }
 
+impl<E: Error + 'static> Traced<E> {
+    /// Returns an iterator over the chain of source errors of this
+    /// [`Traced`] wrapper, starting with the wrapped error itself, followed
+    /// by each [`Error::source()`] transitively.
+    #[must_use]
+    pub fn chain(&self) -> Chain<'_> {
+        Chain(Some(&self.err))
+    }
+
+    /// Bundles this [`Traced`] wrapper into a structured [`Report`],
+    /// combining the head error's [`Display`](fmt::Display) representation,
+    /// its source [`chain`](Self::chain), and the captured [`Frame`]s, ready
+    /// to be serialized into structured loggers/observability pipelines.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_report(&self) -> Report {
+        Report {
+            error: self.err.to_string(),
+            causes: self.chain().skip(1).map(ToString::to_string).collect(),
+            frames: self.trace.to_vec(),
+        }
+    }
+}
+
+// NB: This impl is bound on `E: Error`, not just `E: Display` as the rest of
+//     `Traced`'s API is (e.g. `into_inner()`/`split()`/`compose()` have no
+//     `Error` bound at all). Walking the alternate flag's `Caused by:` source
+//     chain needs `Error::source()`, and Rust cannot pick between an
+//     `E: Error` impl and an `E: Display`-only one for the same trait without
+//     specialization (see the other `TODO`s above on that same limitation),
+//     so `Traced<E>` for a non-`Error` `E` (e.g. `Traced<u8>`) intentionally
+//     no longer implements `Display` as of this feature. This is accepted as
+//     a breaking change for this still-0.1.0 crate.
+impl<E: Error + ?Sized> fmt::Display for Traced<E> {
+    /// Displays the wrapped error.
+    ///
+    /// If the alternate flag (`{:#}`) is specified, additionally displays
+    /// the numbered source chain (as `Caused by:`) and the captured
+    /// [`Trace`] (as `error trace:`) of this [`Traced`] wrapper, similarly
+    /// to how `anyhow::Error`'s `{:?}` does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.err, f)?;
+        if f.alternate() {
+            let mut causes =
+                iter::successors(self.err.source(), |e| (*e).source())
+                    .enumerate()
+                    .peekable();
+            if causes.peek().is_some() {
+                write!(f, "\n\nCaused by:")?;
+                for (i, cause) in causes {
+                    write!(f, "\n{i:>4}: {cause}")?;
+                }
+            }
+            write!(f, "\n\n{}", self.trace)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the chain of source errors of a [`Traced`] wrapper, as
+/// returned by [`Traced::chain()`].
+///
+/// Yields the wrapped error itself, followed by each source transitively
+/// reachable via [`Error::source()`], mirroring `anyhow`'s `Chain` iterator.
+#[derive(Clone, Debug)]
+pub struct Chain<'a>(Option<&'a (dyn Error + 'static)>);
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let err = self.0.take()?;
+        self.0 = err.source();
+        Some(err)
+    }
+}
+
+/// Structured report of a [`Traced`] wrapper, as returned by
+/// [`Traced::to_report()`], ready for emission into structured
+/// loggers/observability pipelines.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Report {
+    /// [`Display`](fmt::Display) representation of the head error.
+    pub error: String,
+
+    /// [`Display`](fmt::Display) representations of the transitive source
+    /// chain errors, not including the head error itself.
+    pub causes: Vec<String>,
+
+    /// Captured [`Frame`]s of the originating [`Trace`].
+    pub frames: Vec<Frame>,
+}
+
+#[cfg(all(test, feature = "backtrace"))]
+thread_local! {
+    /// Per-thread override of [`backtrace_requested()`], used by tests to
+    /// avoid mutating the process-wide `RUST_LIB_BACKTRACE`/`RUST_BACKTRACE`
+    /// environment variables, which other test threads read concurrently.
+    static BACKTRACE_REQUESTED_OVERRIDE: Cell<Option<bool>> =
+        const { Cell::new(None) };
+}
+
+/// Checks whether OS backtrace capturing is requested via the
+/// `RUST_LIB_BACKTRACE`/`RUST_BACKTRACE` environment variables, using the
+/// same precedence as [`Backtrace::capture()`].
+///
+/// Unlike [`Backtrace::capture()`], which caches this decision for the
+/// lifetime of the process on its first call, this re-reads the environment
+/// on every call, so capturing stays responsive to environment changes made
+/// after the first wrap (most notably in tests).
+#[cfg(feature = "backtrace")]
+fn backtrace_requested() -> bool {
+    #[cfg(test)]
+    if let Some(requested) = BACKTRACE_REQUESTED_OVERRIDE.with(Cell::get) {
+        return requested;
+    }
+
+    env::var_os("RUST_LIB_BACKTRACE")
+        .or_else(|| env::var_os("RUST_BACKTRACE"))
+        .map_or(false, |v| v != "0")
+}
+
 /// Trait for wrapping errors into a [`Traced`] wrapper and growing its
 /// [`Trace`] inside.
 ///
@@ -270,7 +433,15 @@ impl<E> WrapTraced<E> for E {
             DEFAULT_FRAMES_CAPACITY.load(Ordering::Relaxed),
         ));
         trace.push(f);
-        Traced { err: self, trace }
+        Traced {
+            err: self,
+            trace,
+            #[cfg(feature = "backtrace")]
+            backtrace: backtrace_requested()
+                .then(Backtrace::force_capture)
+                .filter(|bt| bt.status() == BacktraceStatus::Captured)
+                .map(Arc::new),
+        }
     }
 }
 
@@ -297,6 +468,8 @@ pub fn map_from<F, T: From<F>>(e: Traced<F>) -> Traced<T> {
     Traced {
         err: T::from(e.err),
         trace: e.trace,
+        #[cfg(feature = "backtrace")]
+        backtrace: e.backtrace,
     }
 }
 
@@ -334,6 +507,35 @@ macro_rules! new {
     };
 }
 
+/// Captures a new [`Frame`] in the invocation place, attaches the given
+/// contextual `message` to it, and wraps the given error into a [`Traced`]
+/// wrapper containing this [`Frame`].
+///
+/// If the error represents a [`Traced`] already, then just growths its
+/// [`Trace`] with the captured [`Frame`].
+///
+/// # Example
+///
+/// ```rust
+/// use tracerr::Traced;
+///
+/// let err: u32 = 89;
+/// let err: Traced<u32> = tracerr::context!(err, "while loading config");
+/// assert_eq!(
+///     err.trace().iter().next().and_then(|f| f.message),
+///     Some("while loading config"),
+/// );
+/// ```
+#[macro_export]
+macro_rules! context {
+    ($e:expr, $msg:expr) => {
+        $crate::WrapTraced::wrap_traced(
+            $e,
+            $crate::Frame { message: ::core::option::Option::Some($msg), ..$crate::new_frame!() },
+        )
+    };
+}
+
 /// Captures a new [`Frame`] in the invocation place and wraps the given error
 /// into a [`Traced`] wrapper containing this [`Frame`] with applying the
 /// required [`From`] conversion for the wrapped error.
@@ -458,6 +660,31 @@ mod new_macro_spec {
     }
 }
 
+#[cfg(test)]
+mod context_macro_spec {
+    use super::Traced;
+
+    #[test]
+    fn attaches_message_to_captured_frame() {
+        let err = super::context!((), "while loading config");
+        assert_eq!(err.trace.len(), 1, "creates initial frame");
+        assert_eq!(
+            err.trace[0].message,
+            Some("while loading config"),
+            "attaches the given message",
+        );
+    }
+
+    #[test]
+    fn fills_trace_on_subsequent_calls() {
+        let err = super::context!((), "step one");
+        let err = super::context!(err, "step two");
+        let err: Traced<()> = super::context!(err, "step three");
+        assert_eq!(err.trace.len(), 3, "trace growths with each call");
+        assert_eq!(err.trace[1].message, Some("step two"));
+    }
+}
+
 #[cfg(test)]
 mod map_from_and_new_macro_spec {
     use super::Traced;
@@ -486,7 +713,9 @@ mod wrap_macro_spec {
     #[test]
     fn creates_new_trace_frame_on_initialization() {
         let res: Result<(), ()> = Err(());
-        let err = res.map_err(super::wrap!()).unwrap_err();
+        let Err(err) = res.map_err(super::wrap!()) else {
+            panic!("res is always `Err`");
+        };
         assert_eq!(err.trace.len(), 1, "creates initial frame");
     }
 
@@ -496,7 +725,9 @@ mod wrap_macro_spec {
         let res = res.map_err(super::wrap!());
         let res = res.map_err(super::wrap!());
         let res = res.map_err(super::wrap!(Traced<_>));
-        let err = res.map_err(super::wrap!(=> ())).unwrap_err();
+        let Err(err) = res.map_err(super::wrap!(=> ())) else {
+            panic!("res is always `Err`");
+        };
         assert_eq!(err.trace.len(), 4, "trace growths with each call");
     }
 }
@@ -511,7 +742,9 @@ mod map_from_and_wrap_macro_spec {
         let res = res.map_err(super::wrap!());
         let res = res.map_err(super::map_from_and_wrap!());
         let res = res.map_err(super::map_from_and_wrap!());
-        let err = res.map_err(super::map_from_and_wrap!(=> ())).unwrap_err();
+        let Err(err) = res.map_err(super::map_from_and_wrap!(=> ())) else {
+            panic!("res is always `Err`");
+        };
         assert_eq!(err.trace.len(), 4, "trace growths with each call");
     }
 
@@ -519,8 +752,11 @@ mod map_from_and_wrap_macro_spec {
     fn applies_required_from_implementation() {
         let res: Result<(), u8> = Err(54);
         let res = res.map_err(super::wrap!());
-        let err: Traced<u64> =
-            res.map_err(super::map_from_and_wrap!()).unwrap_err();
+        let Err(err): Result<(), Traced<u64>> =
+            res.map_err(super::map_from_and_wrap!())
+        else {
+            panic!("res is always `Err`");
+        };
         assert!(!err.trace.is_empty(), "captures frames");
     }
 }
@@ -535,15 +771,163 @@ mod from_and_wrap_macro_spec {
         let res = res.map_err(super::wrap!());
         let res = res.map_err(super::from_and_wrap!());
         let res = res.map_err(super::from_and_wrap!());
-        let err = res.map_err(super::from_and_wrap!(=> ())).unwrap_err();
+        let Err(err) = res.map_err(super::from_and_wrap!(=> ())) else {
+            panic!("res is always `Err`");
+        };
         assert_eq!(err.trace.len(), 4, "trace growths with each call");
     }
 
     #[test]
     fn applies_required_from_implementation() {
         let res: Result<(), u8> = Err(54);
-        let err: Traced<u64> =
-            res.map_err(super::from_and_wrap!()).unwrap_err();
+        let Err(err): Result<(), Traced<u64>> =
+            res.map_err(super::from_and_wrap!())
+        else {
+            panic!("res is always `Err`");
+        };
         assert!(!err.trace.is_empty(), "captures frames");
     }
 }
+
+#[cfg(test)]
+mod chain_spec {
+    use std::error::Error;
+
+    use derive_more::with_trait::Display;
+
+    #[derive(Debug, Display)]
+    #[display("{_0}")]
+    struct Inner(&'static str);
+
+    impl Error for Inner {}
+
+    #[derive(Debug, Display)]
+    #[display("{_0}")]
+    struct Outer(&'static str, Inner);
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.1)
+        }
+    }
+
+    #[test]
+    fn yields_self_then_transitive_sources() {
+        let err = super::new!(Outer("outer failed", Inner("inner failed")));
+
+        let messages: Vec<_> =
+            err.chain().map(ToString::to_string).collect();
+        assert_eq!(messages, vec!["outer failed", "inner failed"]);
+    }
+
+    #[test]
+    fn alternate_display_renders_causes_and_trace() {
+        let err = super::new!(Outer("outer failed", Inner("inner failed")));
+
+        let rendered = format!("{err:#}");
+        assert!(rendered.starts_with("outer failed\n\nCaused by:"));
+        assert!(rendered.contains("   0: inner failed"));
+        assert!(rendered.ends_with(&err.trace().to_string()));
+    }
+
+    #[test]
+    fn compact_display_renders_only_head_error() {
+        let err = super::new!(Outer("outer failed", Inner("inner failed")));
+
+        assert_eq!(err.to_string(), "outer failed");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "backtrace")]
+mod backtrace_spec {
+    use std::sync::Arc;
+
+    use super::BACKTRACE_REQUESTED_OVERRIDE;
+
+    /// RAII guard overriding [`super::backtrace_requested()`] for the
+    /// current thread only, instead of mutating the real process-wide
+    /// `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE` env vars, which other test
+    /// threads read concurrently.
+    struct BacktraceRequestedGuard;
+
+    impl BacktraceRequestedGuard {
+        fn set(requested: bool) -> Self {
+            BACKTRACE_REQUESTED_OVERRIDE.with(|o| o.set(Some(requested)));
+            Self
+        }
+    }
+
+    impl Drop for BacktraceRequestedGuard {
+        fn drop(&mut self) {
+            BACKTRACE_REQUESTED_OVERRIDE.with(|o| o.set(None));
+        }
+    }
+
+    #[test]
+    fn captures_backtrace_only_on_first_wrap() {
+        let _guard = BacktraceRequestedGuard::set(true);
+
+        let err = super::new!(());
+        assert!(
+            err.backtrace().is_some(),
+            "captures a backtrace on first wrap",
+        );
+
+        let Some(first) = err.backtrace.as_ref().map(Arc::as_ptr) else {
+            panic!("backtrace was just asserted");
+        };
+        let err: super::Traced<()> = super::new!(err);
+        let Some(second) = err.backtrace.as_ref().map(Arc::as_ptr) else {
+            panic!("backtrace was just asserted");
+        };
+
+        assert_eq!(first, second, "keeps the original backtrace");
+    }
+
+    #[test]
+    fn skips_disabled_backtrace() {
+        let _guard = BacktraceRequestedGuard::set(false);
+
+        let err = super::new!(());
+        assert!(
+            err.backtrace().is_none(),
+            "skips a disabled backtrace instead of storing a useless one",
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod to_report_spec {
+    use std::error::Error;
+
+    use derive_more::with_trait::Display;
+
+    #[derive(Debug, Display)]
+    #[display("outer failed")]
+    struct Outer;
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&Inner)
+        }
+    }
+
+    #[derive(Debug, Display)]
+    #[display("inner failed")]
+    struct Inner;
+
+    impl Error for Inner {}
+
+    #[test]
+    fn bundles_error_causes_and_frames() {
+        let err = super::new!(Outer);
+        let err: super::Traced<Outer> = super::new!(err);
+        let report = err.to_report();
+
+        assert_eq!(report.error, "outer failed");
+        assert_eq!(report.causes, vec!["inner failed".to_owned()]);
+        assert_eq!(report.frames.len(), 2, "captures all trace frames");
+    }
+}
@@ -8,8 +8,8 @@ use std::{
 use derive_more::with_trait::Display;
 
 /// Captured frame of [`Trace`].
-#[derive(Clone, Copy, Debug, Display)]
-#[display("{module}\n  at {file}:{line}")]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Frame {
     /// Name of source file where [`Frame`] is captured.
     pub file: &'static str,
@@ -19,18 +19,43 @@ pub struct Frame {
 
     /// Absolute name of module where [`Frame`] is captured.
     pub module: &'static str,
+
+    /// Optional contextual message describing why this [`Frame`] has been
+    /// captured, attached via [`tracerr::context!`](crate::context).
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<&'static str>,
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(message) = self.message {
+            write!(f, "{} — {message}", self.module)?;
+        } else {
+            write!(f, "{}", self.module)?;
+        }
+        write!(f, "\n  at {}:{}", self.file, self.line)
+    }
 }
 
 /// Captures and returns new [`Frame`] in the macro invocation place.
 #[macro_export]
 macro_rules! new_frame {
     () => {
-        $crate::Frame { file: file!(), line: line!(), module: module_path!() }
+        $crate::Frame {
+            file: file!(),
+            line: line!(),
+            module: module_path!(),
+            message: None,
+        }
     };
 }
 
 /// Trace composed from captured [`Frame`]s.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Trace(Vec<Frame>);
 
 impl Trace {
@@ -72,10 +97,62 @@ mod frame_spec {
 
     #[test]
     fn displays_module_followed_by_file_and_line() {
-        let frame = Frame { file: "my_file.rs", line: 32, module: "main::sub" };
+        let frame = Frame {
+            file: "my_file.rs",
+            line: 32,
+            module: "main::sub",
+            message: None,
+        };
 
         assert_eq!(frame.to_string(), "main::sub\n  at my_file.rs:32");
     }
+
+    #[test]
+    fn displays_message_when_present() {
+        let frame = Frame {
+            file: "my_file.rs",
+            line: 32,
+            module: "main::sub",
+            message: Some("while loading config"),
+        };
+
+        assert_eq!(
+            frame.to_string(),
+            "main::sub — while loading config\n  at my_file.rs:32",
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod frame_serde_spec {
+    use super::Frame;
+
+    #[test]
+    fn serializes_message_only_when_present() {
+        let frame = Frame {
+            file: "my_file.rs",
+            line: 32,
+            module: "main::sub",
+            message: None,
+        };
+        let Ok(json) = serde_json::to_string(&frame) else {
+            panic!("`Frame` is always serializable");
+        };
+        assert_eq!(
+            json,
+            r#"{"file":"my_file.rs","line":32,"module":"main::sub"}"#,
+        );
+
+        let frame = Frame { message: Some("while loading config"), ..frame };
+        let Ok(json_with_message) = serde_json::to_string(&frame) else {
+            panic!("`Frame` is always serializable");
+        };
+        assert_eq!(
+            json_with_message,
+            r#"{"file":"my_file.rs","line":32,"module":"main::sub","message":"while loading config"}"#,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -85,25 +162,68 @@ mod trace_spec {
     #[test]
     fn displays_frames_separated_by_blank_line() {
         let stack = Trace(vec![
-            Frame { file: "src/my_file.rs", line: 32, module: "main::sub1" },
+            Frame {
+                file: "src/my_file.rs",
+                line: 32,
+                module: "main::sub1",
+                message: None,
+            },
             Frame {
                 file: "anywhere/my_file.rs",
                 line: 54,
                 module: "main::sub2",
+                message: None,
+            },
+            Frame {
+                file: "file.rs",
+                line: 232,
+                module: "main::sub3",
+                message: None,
             },
-            Frame { file: "file.rs", line: 232, module: "main::sub3" },
         ]);
 
         assert_eq!(
             format!("{stack}\n            "),
-            r#"error trace:
+            "error trace:
 main::sub1
   at src/my_file.rs:32
 main::sub2
   at anywhere/my_file.rs:54
 main::sub3
   at file.rs:232
-            "#,
+            ",
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod trace_serde_spec {
+    use super::{Frame, Trace};
+
+    #[test]
+    fn serializes_as_a_flat_array_of_frames() {
+        let stack = Trace(vec![
+            Frame {
+                file: "my_file.rs",
+                line: 32,
+                module: "main::sub",
+                message: None,
+            },
+            Frame {
+                file: "other_file.rs",
+                line: 54,
+                module: "main::sub2",
+                message: Some("while loading config"),
+            },
+        ]);
+
+        let Ok(json) = serde_json::to_string(&stack) else {
+            panic!("`Trace` is always serializable");
+        };
+        assert_eq!(
+            json,
+            r#"[{"file":"my_file.rs","line":32,"module":"main::sub"},{"file":"other_file.rs","line":54,"module":"main::sub2","message":"while loading config"}]"#,
         );
     }
 }